@@ -6,9 +6,16 @@
 //! - IPC between frontend and Python engine
 
 use serde::{Deserialize, Serialize};
-use std::sync::Mutex;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::oneshot;
 use tauri::{
-    AppHandle, Emitter, Manager, State, PhysicalPosition,
+    AppHandle, Emitter, Manager, State, PhysicalPosition, PhysicalSize,
+    WebviewUrl, WebviewWindow, WebviewWindowBuilder,
     menu::{Menu, MenuItem},
     tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent},
 };
@@ -19,19 +26,117 @@ use tauri_plugin_autostart::MacosLauncher;
 struct SidecarState {
     is_running: Mutex<bool>,
     child: Mutex<Option<CommandChild>>,
+    /// When the currently-running instance was spawned, used to decide
+    /// whether it survived long enough to reset the failure counter
+    started_at: Mutex<Option<Instant>>,
+    /// Timestamps of recent restarts, used to detect a rapid-crash loop
+    recent_restarts: Mutex<Vec<Instant>>,
+    /// True once the user (or a command) deliberately stopped the sidecar,
+    /// so the supervisor doesn't try to auto-restart it
+    manually_stopped: Mutex<bool>,
+    /// Last lines of stderr captured from the sidecar, kept for the
+    /// `sidecar-failed` event so the UI can show what went wrong
+    last_stderr_lines: Mutex<VecDeque<String>>,
 }
 
+/// Base backoff delay for sidecar restarts
+const SIDECAR_BACKOFF_BASE_SECS: u64 = 2;
+/// Cap on the exponential backoff delay
+const SIDECAR_BACKOFF_MAX_SECS: u64 = 60;
+/// How many restarts within `SIDECAR_RAPID_RESTART_WINDOW_SECS` before we give up
+const SIDECAR_MAX_RAPID_RESTARTS: usize = 5;
+/// Window in which restarts count as "rapid" for the give-up check
+const SIDECAR_RAPID_RESTART_WINDOW_SECS: u64 = 120;
+/// How long a sidecar must stay up before we consider it stable and reset the failure counter
+const SIDECAR_STABILITY_WINDOW_SECS: u64 = 30;
+/// How many trailing stderr lines to keep for the `sidecar-failed` report
+const SIDECAR_STDERR_HISTORY_LINES: usize = 20;
+
 /// State for pending break (shared between windows)
 struct PendingBreakState {
     break_data: Mutex<Option<serde_json::Value>>,
 }
 
+/// State for the idle-detection subsystem
+struct IdleState {
+    /// Minutes of system-wide input idle time before we auto-pause
+    threshold_minutes: Mutex<u64>,
+    /// Whether idle detection is enabled at all
+    enabled: Mutex<bool>,
+    /// True once we've auto-paused the session because of idleness
+    auto_paused: Mutex<bool>,
+    /// True if the user explicitly paused from the tray/UI; idle detection
+    /// must never auto-resume a session it didn't itself pause
+    user_paused: Mutex<bool>,
+}
+
+/// State for the microphone-based meeting detector
+struct AudioMonitorState {
+    /// Rolling RMS level of the default input device, updated from the
+    /// `cpal` capture callback
+    level: Arc<Mutex<f32>>,
+    /// RMS level above which input counts as "sustained speech"
+    threshold: Mutex<f32>,
+    /// Whether meeting detection is enabled at all. Shared with the capture
+    /// thread (which isn't a Tauri-managed task) so it can avoid opening the
+    /// microphone until the user actually turns detection on.
+    enabled: Arc<Mutex<bool>>,
+    /// Seconds the level has stayed continuously above `threshold`;
+    /// `is_in_meeting` flags "in meeting" once this reaches `MEETING_SUSTAINED_SECONDS`
+    sustained_seconds_above_threshold: Mutex<f32>,
+}
+
 /// Events emitted from the Python sidecar
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct SidecarEvent {
     #[serde(rename = "type")]
     event_type: String,
     data: Option<serde_json::Value>,
+    /// Echoed back from a correlated query (see `PendingRequests`); absent
+    /// on unsolicited push events like `break_due`
+    #[serde(default)]
+    request_id: Option<u64>,
+}
+
+/// Tracks in-flight request/response pairs with the sidecar so query
+/// commands (`get_status`, `get_settings`, ...) can await their answer
+/// instead of racing a separately-listened-for event.
+struct PendingRequests {
+    next_id: Mutex<u64>,
+    senders: Mutex<HashMap<u64, oneshot::Sender<serde_json::Value>>>,
+}
+
+/// How long a correlated query waits for the sidecar to answer before giving up
+const SIDECAR_QUERY_TIMEOUT_SECS: u64 = 5;
+
+/// Write a command to the sidecar tagged with a fresh `request_id`, and await
+/// the matching response picked up by the stdout event loop.
+async fn send_sidecar_query(app: &AppHandle, mut command: serde_json::Value) -> Result<serde_json::Value, String> {
+    let pending = app.state::<PendingRequests>();
+    let request_id = {
+        let mut next_id = pending.next_id.lock().unwrap();
+        let id = *next_id;
+        *next_id += 1;
+        id
+    };
+
+    if let Some(obj) = command.as_object_mut() {
+        obj.insert("request_id".to_string(), serde_json::json!(request_id));
+    }
+
+    let (tx, rx) = oneshot::channel();
+    pending.senders.lock().unwrap().insert(request_id, tx);
+
+    write_to_sidecar(app, command);
+
+    match tokio::time::timeout(std::time::Duration::from_secs(SIDECAR_QUERY_TIMEOUT_SECS), rx).await {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(_)) => Err("Sidecar closed the connection before responding".to_string()),
+        Err(_) => {
+            pending.senders.lock().unwrap().remove(&request_id);
+            Err(format!("Timed out waiting for sidecar response after {}s", SIDECAR_QUERY_TIMEOUT_SECS))
+        }
+    }
 }
 
 /// Send a command to the Python sidecar via stdin
@@ -56,6 +161,37 @@ fn is_sidecar_running(state: State<SidecarState>) -> bool {
     *state.is_running.lock().unwrap()
 }
 
+/// Stop the Python sidecar: ask it to shut down cleanly, give it a moment,
+/// then kill the process if it's still around. Marks it as manually stopped
+/// so the supervisor doesn't auto-restart it.
+#[tauri::command]
+async fn stop_sidecar(app: AppHandle) -> Result<(), String> {
+    let state = app.state::<SidecarState>();
+    *state.manually_stopped.lock().unwrap() = true;
+
+    write_to_sidecar(&app, serde_json::json!({ "cmd": "shutdown" }));
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+
+    let mut child_guard = state.child.lock().map_err(|e| e.to_string())?;
+    if let Some(mut child) = child_guard.take() {
+        let _ = child.kill();
+    }
+    *state.is_running.lock().unwrap() = false;
+    Ok(())
+}
+
+/// Manually restart the sidecar, resetting the failure/backoff state so a
+/// user-initiated restart isn't treated as part of a crash loop.
+#[tauri::command]
+async fn restart_sidecar(app: AppHandle) -> Result<(), String> {
+    stop_sidecar(app.clone()).await?;
+    let state = app.state::<SidecarState>();
+    state.recent_restarts.lock().unwrap().clear();
+    *state.manually_stopped.lock().unwrap() = false;
+    start_sidecar(&app);
+    Ok(())
+}
+
 /// Log hydration with quick amounts
 #[tauri::command]
 async fn log_hydration(app: AppHandle, amount_ml: i32) -> Result<(), String> {
@@ -66,13 +202,231 @@ async fn log_hydration(app: AppHandle, amount_ml: i32) -> Result<(), String> {
     send_to_sidecar(app, cmd).await
 }
 
+/// Base label for the primary break overlay window; mirrored overlays on
+/// additional monitors are named `overlay-monitor-{index}`
+const OVERLAY_BASE_LABEL: &str = "overlay";
+
+/// Label for the break overlay mirrored onto the monitor at `index`
+fn overlay_label(index: usize) -> String {
+    if index == 0 {
+        OVERLAY_BASE_LABEL.to_string()
+    } else {
+        format!("overlay-monitor-{}", index)
+    }
+}
+
+/// Base label for the primary schedule-warning notification window; mirrored
+/// copies on additional monitors are named `notification-monitor-{index}`
+const NOTIFICATION_BASE_LABEL: &str = "notification";
+
+/// Hardcoded notification window size (must match tauri.conf.json)
+const NOTIFICATION_WINDOW_SIZE: (i32, i32) = (280, 320);
+const NOTIFICATION_WINDOW_PADDING: i32 = 20;
+
+/// Whether the schedule-warning notification should be broadcast to every
+/// connected monitor, or stay on just the one the notification window is on
+struct MultiMonitorOverlayState {
+    enabled: Mutex<bool>,
+}
+
+/// Toggle whether the schedule-warning notification broadcasts to every
+/// monitor or stays on a single one
+#[tauri::command]
+fn set_multi_monitor_overlay_enabled(state: State<MultiMonitorOverlayState>, enabled: bool) {
+    *state.enabled.lock().unwrap() = enabled;
+}
+
+fn notification_label(index: usize) -> String {
+    if index == 0 {
+        NOTIFICATION_BASE_LABEL.to_string()
+    } else {
+        format!("notification-monitor-{}", index)
+    }
+}
+
+/// Hide every break overlay instance, not just the primary one, along with
+/// every schedule-warning notification window
+fn hide_all_break_overlays(app: &AppHandle) {
+    for (label, window) in app.webview_windows() {
+        if label == OVERLAY_BASE_LABEL || label.starts_with("overlay-monitor-")
+            || label == NOTIFICATION_BASE_LABEL || label.starts_with("notification-monitor-")
+        {
+            let _ = window.hide();
+        }
+    }
+}
+
+/// Show the schedule-warning notification mirrored across every connected
+/// monitor, bottom-right anchored on each, falling back to the single
+/// primary window on the monitor it currently sits on (or the primary
+/// monitor) when multi-monitor broadcast is disabled. The primary window is
+/// the exception: if a position has been saved for it by the window-geometry
+/// persistence in `save_window_state_for`/`restore_window_state_for`, that
+/// position is left alone instead of being snapped back to the bottom-right
+/// default.
+fn show_notification_overlays(app: &AppHandle, payload: serde_json::Value) {
+    let primary = match app.get_webview_window(NOTIFICATION_BASE_LABEL) {
+        Some(w) => w,
+        None => return,
+    };
+
+    let has_pinned_position = load_window_states(app)
+        .get(NOTIFICATION_BASE_LABEL)
+        .is_some_and(|g| g.x.is_some() && g.y.is_some());
+
+    let multi_monitor = app.try_state::<MultiMonitorOverlayState>()
+        .map(|s| *s.enabled.lock().unwrap())
+        .unwrap_or(false);
+
+    let monitors = if multi_monitor {
+        primary.available_monitors().ok().filter(|m| !m.is_empty())
+    } else {
+        None
+    };
+    let monitors = monitors.or_else(|| primary.current_monitor().ok().flatten().map(|m| vec![m]))
+        .or_else(|| primary.primary_monitor().ok().flatten().map(|m| vec![m]));
+    let monitors = match monitors {
+        Some(m) => m,
+        None => return,
+    };
+
+    for (index, monitor) in monitors.iter().enumerate() {
+        let label = notification_label(index);
+        let window = match app.get_webview_window(&label) {
+            Some(w) => w,
+            None => {
+                let url = primary.url().map(WebviewUrl::External).unwrap_or(WebviewUrl::App("notification".into()));
+                match WebviewWindowBuilder::new(app, &label, url)
+                    .title("Aura Notification")
+                    .inner_size(NOTIFICATION_WINDOW_SIZE.0 as f64, NOTIFICATION_WINDOW_SIZE.1 as f64)
+                    .decorations(false)
+                    .always_on_top(true)
+                    .skip_taskbar(true)
+                    .visible(false)
+                    .build()
+                {
+                    Ok(w) => w,
+                    Err(e) => {
+                        log::error!("[Aura] Failed to create notification overlay for monitor {}: {}", index, e);
+                        continue;
+                    }
+                }
+            }
+        };
+
+        // The primary window keeps its pinned position; mirrors on other
+        // monitors have no pinned state of their own, so they always get
+        // the bottom-right default relative to their own monitor.
+        if !(index == 0 && has_pinned_position) {
+            let screen_size = monitor.size();
+            let monitor_pos = monitor.position();
+            let x = monitor_pos.x + screen_size.width as i32 - NOTIFICATION_WINDOW_SIZE.0 - NOTIFICATION_WINDOW_PADDING;
+            let y = monitor_pos.y + screen_size.height as i32 - NOTIFICATION_WINDOW_SIZE.1 - NOTIFICATION_WINDOW_PADDING;
+            let _ = window.set_position(PhysicalPosition::new(x, y));
+        }
+        let _ = window.show();
+        let _ = window.set_always_on_top(true);
+
+        queue_or_emit(app, Some(&label), "show-schedule-warning", payload.clone());
+    }
+
+    let mut stale_index = monitors.len();
+    while let Some(window) = app.get_webview_window(&notification_label(stale_index)) {
+        let _ = window.close();
+        stale_index += 1;
+    }
+}
+
+/// Show the schedule-warning overlay on every connected monitor regardless
+/// of the multi-monitor setting - used by the settings UI to let the user
+/// preview the behavior
+#[tauri::command]
+fn show_overlay_all(app: AppHandle) -> Result<(), String> {
+    let previous = app.try_state::<MultiMonitorOverlayState>()
+        .map(|state| std::mem::replace(&mut *state.enabled.lock().unwrap(), true));
+    show_notification_overlays(&app, serde_json::json!({
+        "title": "Schedule Warning",
+        "action": "pause",
+        "seconds_remaining": 60
+    }));
+    if let (Some(state), Some(previous)) = (app.try_state::<MultiMonitorOverlayState>(), previous) {
+        *state.enabled.lock().unwrap() = previous;
+    }
+    Ok(())
+}
+
+/// Show the break overlay mirrored across every connected monitor, creating
+/// or repositioning one webview per monitor and rebuilding the set each time
+/// in case a monitor was added/removed since the last break. The monitor the
+/// primary overlay currently sits on owns the countdown focus so keyboard
+/// input isn't handled by more than one window at once.
+fn show_break_overlays_all_monitors(app: &AppHandle, break_data: serde_json::Value) {
+    let primary = match app.get_webview_window(OVERLAY_BASE_LABEL) {
+        Some(w) => w,
+        None => return,
+    };
+
+    let monitors = primary.available_monitors().ok().filter(|m| !m.is_empty());
+    let monitors = match monitors {
+        Some(m) => m,
+        None => match primary.primary_monitor().ok().flatten() {
+            Some(m) => vec![m],
+            None => return,
+        },
+    };
+
+    let focus_index = primary.current_monitor().ok().flatten()
+        .and_then(|current| monitors.iter().position(|m| m.position() == current.position()))
+        .unwrap_or(0);
+
+    for (index, monitor) in monitors.iter().enumerate() {
+        let label = overlay_label(index);
+        let window = match app.get_webview_window(&label) {
+            Some(w) => w,
+            None => {
+                let url = primary.url().map(WebviewUrl::External).unwrap_or(WebviewUrl::App("overlay".into()));
+                match WebviewWindowBuilder::new(app, &label, url)
+                    .title("Aura Break")
+                    .decorations(false)
+                    .always_on_top(true)
+                    .skip_taskbar(true)
+                    .visible(false)
+                    .build()
+                {
+                    Ok(w) => w,
+                    Err(e) => {
+                        log::error!("[Aura] Failed to create overlay for monitor {}: {}", index, e);
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let position = monitor.position();
+        let size = monitor.size();
+        let _ = window.set_position(PhysicalPosition::new(position.x, position.y));
+        let _ = window.set_size(PhysicalSize::new(size.width, size.height));
+        let _ = window.show();
+        if index == focus_index {
+            let _ = window.set_focus();
+        }
+
+        queue_or_emit(app, Some(&label), "show-break", break_data.clone());
+    }
+
+    // Close any overlay windows left over from a previous break on a monitor
+    // set that's since shrunk
+    let mut stale_index = monitors.len();
+    while let Some(window) = app.get_webview_window(&overlay_label(stale_index)) {
+        let _ = window.close();
+        stale_index += 1;
+    }
+}
+
 /// Complete a break
 #[tauri::command]
 async fn complete_break(app: AppHandle) -> Result<(), String> {
-    // Hide overlay first
-    if let Some(overlay) = app.get_webview_window("overlay") {
-        let _ = overlay.hide();
-    }
+    hide_all_break_overlays(&app);
     let cmd = serde_json::json!({ "cmd": "complete_break" });
     send_to_sidecar(app, cmd).await
 }
@@ -80,10 +434,7 @@ async fn complete_break(app: AppHandle) -> Result<(), String> {
 /// Snooze a break
 #[tauri::command]
 async fn snooze_break(app: AppHandle, minutes: i32) -> Result<(), String> {
-    // Hide overlay first
-    if let Some(overlay) = app.get_webview_window("overlay") {
-        let _ = overlay.hide();
-    }
+    hide_all_break_overlays(&app);
     let cmd = serde_json::json!({
         "cmd": "snooze_break",
         "minutes": minutes
@@ -94,10 +445,7 @@ async fn snooze_break(app: AppHandle, minutes: i32) -> Result<(), String> {
 /// Skip a break
 #[tauri::command]
 async fn skip_break(app: AppHandle) -> Result<(), String> {
-    // Hide overlay first
-    if let Some(overlay) = app.get_webview_window("overlay") {
-        let _ = overlay.hide();
-    }
+    hide_all_break_overlays(&app);
     let cmd = serde_json::json!({ "cmd": "skip_break" });
     send_to_sidecar(app, cmd).await
 }
@@ -139,24 +487,10 @@ async fn trigger_test_break(app: AppHandle, break_type: String, duration_seconds
     // Store in shared state so overlay can retrieve it
     let pending_state = app.state::<PendingBreakState>();
     *pending_state.break_data.lock().unwrap() = Some(break_data.clone());
-    
-    // Show the overlay window
-    if let Some(overlay) = app.get_webview_window("overlay") {
-        overlay.show().map_err(|e| e.to_string())?;
-        overlay.set_focus().map_err(|e| e.to_string())?;
-        
-        // Wait for overlay to initialize, then emit the event
-        let app_clone = app.clone();
-        let break_data_clone = break_data.clone();
-        tauri::async_runtime::spawn(async move {
-            // Give the overlay window time to set up its event listeners
-            tokio::time::sleep(std::time::Duration::from_millis(300)).await;
-            if let Some(overlay) = app_clone.get_webview_window("overlay") {
-                let _ = overlay.emit("show-break", break_data_clone);
-            }
-        });
-    }
-    
+
+    // Show the overlay on every connected monitor
+    show_break_overlays_all_monitors(&app, break_data);
+
     Ok(())
 }
 
@@ -178,6 +512,9 @@ fn clear_pending_break(app: AppHandle) {
 /// Pause reminders
 #[tauri::command]
 async fn pause_reminders(app: AppHandle, minutes: Option<i32>) -> Result<(), String> {
+    if let Some(idle_state) = app.try_state::<IdleState>() {
+        *idle_state.user_paused.lock().unwrap() = true;
+    }
     let cmd = serde_json::json!({
         "cmd": "pause",
         "minutes": minutes
@@ -188,29 +525,32 @@ async fn pause_reminders(app: AppHandle, minutes: Option<i32>) -> Result<(), Str
 /// Resume reminders
 #[tauri::command]
 async fn resume_reminders(app: AppHandle) -> Result<(), String> {
+    if let Some(idle_state) = app.try_state::<IdleState>() {
+        *idle_state.user_paused.lock().unwrap() = false;
+    }
     let cmd = serde_json::json!({ "cmd": "resume" });
     send_to_sidecar(app, cmd).await
 }
 
 /// Get current status
 #[tauri::command]
-async fn get_status(app: AppHandle) -> Result<(), String> {
+async fn get_status(app: AppHandle) -> Result<serde_json::Value, String> {
     let cmd = serde_json::json!({ "cmd": "get_status" });
-    send_to_sidecar(app, cmd).await
+    send_sidecar_query(&app, cmd).await
 }
 
 /// Get training data stats
 #[tauri::command]
-async fn get_training_stats(app: AppHandle) -> Result<(), String> {
+async fn get_training_stats(app: AppHandle) -> Result<serde_json::Value, String> {
     let cmd = serde_json::json!({ "cmd": "get_training_stats" });
-    send_to_sidecar(app, cmd).await
+    send_sidecar_query(&app, cmd).await
 }
 
 /// Get all settings
 #[tauri::command]
-async fn get_settings(app: AppHandle) -> Result<(), String> {
+async fn get_settings(app: AppHandle) -> Result<serde_json::Value, String> {
     let cmd = serde_json::json!({ "cmd": "get_settings" });
-    send_to_sidecar(app, cmd).await
+    send_sidecar_query(&app, cmd).await
 }
 
 /// Update a single setting
@@ -268,9 +608,9 @@ async fn end_session(app: AppHandle) -> Result<(), String> {
 
 /// Get all schedule rules
 #[tauri::command]
-async fn get_schedule_rules(app: AppHandle) -> Result<(), String> {
+async fn get_schedule_rules(app: AppHandle) -> Result<serde_json::Value, String> {
     let cmd = serde_json::json!({ "cmd": "get_schedule_rules" });
-    send_to_sidecar(app, cmd).await
+    send_sidecar_query(&app, cmd).await
 }
 
 /// Add a new schedule rule
@@ -318,6 +658,672 @@ async fn reset_all_timers(app: AppHandle) -> Result<(), String> {
     send_to_sidecar(app, cmd).await
 }
 
+/// Input within this many ms counts as activity, and activity must still be
+/// seen at least this long after it was first noticed before we consider the
+/// user "resumed" - guards against a single stray mouse jiggle resuming a
+/// session that's been idle for hours.
+const IDLE_RESUME_DEBOUNCE_MS: u64 = 2_000;
+
+/// Poll interval for the idle-detection task
+const IDLE_POLL_INTERVAL_MS: u64 = 5_000;
+
+/// Query how long the system has been idle, in milliseconds.
+///
+/// On Windows this uses `GetLastInputInfo`/`GetTickCount`
+/// (idle_ms = GetTickCount - dwTime). On other platforms there is no
+/// portable equivalent yet, so we report 0 (never idle).
+#[cfg(target_os = "windows")]
+fn system_idle_ms() -> u64 {
+    use windows::Win32::System::SystemInformation::GetTickCount;
+    use windows::Win32::UI::Input::KeyboardAndMouse::{GetLastInputInfo, LASTINPUTINFO};
+
+    let mut info = LASTINPUTINFO {
+        cbSize: std::mem::size_of::<LASTINPUTINFO>() as u32,
+        dwTime: 0,
+    };
+    unsafe {
+        if GetLastInputInfo(&mut info).as_bool() {
+            let now = GetTickCount();
+            return now.wrapping_sub(info.dwTime) as u64;
+        }
+    }
+    0
+}
+
+#[cfg(not(target_os = "windows"))]
+fn system_idle_ms() -> u64 {
+    0
+}
+
+/// Set the idle threshold, in minutes, after which we auto-pause the session
+#[tauri::command]
+fn set_idle_threshold(state: State<IdleState>, minutes: u64) {
+    *state.threshold_minutes.lock().unwrap() = minutes;
+}
+
+/// Get the current idle-detection status for the settings UI
+#[tauri::command]
+fn get_idle_status(state: State<IdleState>) -> serde_json::Value {
+    serde_json::json!({
+        "enabled": *state.enabled.lock().unwrap(),
+        "threshold_minutes": *state.threshold_minutes.lock().unwrap(),
+        "auto_paused": *state.auto_paused.lock().unwrap(),
+        "idle_ms": system_idle_ms(),
+    })
+}
+
+/// Enable or disable idle detection entirely
+#[tauri::command]
+fn set_idle_detection_enabled(state: State<IdleState>, enabled: bool) {
+    *state.enabled.lock().unwrap() = enabled;
+}
+
+/// Poll system idle time on a Tokio interval and auto-pause/resume the
+/// session as the user steps away and comes back.
+fn start_idle_monitor(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_millis(IDLE_POLL_INTERVAL_MS));
+        let mut resume_candidate_since: Option<std::time::Instant> = None;
+
+        loop {
+            interval.tick().await;
+
+            let idle_state = match app.try_state::<IdleState>() {
+                Some(state) => state,
+                None => continue,
+            };
+
+            if !*idle_state.enabled.lock().unwrap() {
+                continue;
+            }
+
+            let threshold_ms = *idle_state.threshold_minutes.lock().unwrap() * 60 * 1000;
+            let idle_ms = system_idle_ms();
+            let was_auto_paused = *idle_state.auto_paused.lock().unwrap();
+
+            if !was_auto_paused && idle_ms >= threshold_ms {
+                // Don't auto-pause a session the user already paused manually
+                if *idle_state.user_paused.lock().unwrap() {
+                    continue;
+                }
+                *idle_state.auto_paused.lock().unwrap() = true;
+                write_to_sidecar(&app, serde_json::json!({ "cmd": "pause_session" }));
+                let _ = app.emit("sidecar-auto-paused", serde_json::json!({ "idle_ms": idle_ms }));
+                resume_candidate_since = None;
+            } else if was_auto_paused && idle_ms < IDLE_RESUME_DEBOUNCE_MS && !*idle_state.user_paused.lock().unwrap() {
+                // Input has resumed - debounce before treating it as "back".
+                // Skip if the user has since paused explicitly from the tray;
+                // that pause should stick until they resume it themselves.
+                let now = std::time::Instant::now();
+                let since = *resume_candidate_since.get_or_insert(now);
+                if now.duration_since(since).as_millis() as u64 >= IDLE_RESUME_DEBOUNCE_MS {
+                    *idle_state.auto_paused.lock().unwrap() = false;
+                    write_to_sidecar(&app, serde_json::json!({ "cmd": "resume_session" }));
+                    let _ = app.emit("sidecar-auto-resumed", serde_json::json!({}));
+                    resume_candidate_since = None;
+                }
+            } else {
+                resume_candidate_since = None;
+            }
+        }
+    });
+}
+
+/// Seconds of sustained input above the mic threshold before we flag "in meeting"
+const MEETING_SUSTAINED_SECONDS: f32 = 8.0;
+
+/// Default RMS threshold above which input counts as speech
+const DEFAULT_MIC_THRESHOLD: f32 = 0.02;
+
+/// Report the current rolling microphone input level (0.0-1.0 RMS)
+#[tauri::command]
+fn get_audio_level(state: State<AudioMonitorState>) -> f32 {
+    *state.level.lock().unwrap()
+}
+
+/// Set the RMS threshold above which input counts as sustained speech
+#[tauri::command]
+fn set_mic_threshold(state: State<AudioMonitorState>, threshold: f32) {
+    *state.threshold.lock().unwrap() = threshold;
+}
+
+/// Enable or disable meeting detection entirely
+#[tauri::command]
+fn set_meeting_detection_enabled(state: State<AudioMonitorState>, enabled: bool) {
+    *state.enabled.lock().unwrap() = enabled;
+    if !enabled {
+        *state.sustained_seconds_above_threshold.lock().unwrap() = 0.0;
+    }
+}
+
+/// Whether the user currently appears to be in a meeting/call, based on
+/// sustained microphone input above the configured threshold
+fn is_in_meeting(app: &AppHandle) -> bool {
+    match app.try_state::<AudioMonitorState>() {
+        Some(state) => {
+            *state.enabled.lock().unwrap()
+                && *state.sustained_seconds_above_threshold.lock().unwrap() >= MEETING_SUSTAINED_SECONDS
+        }
+        None => false,
+    }
+}
+
+/// Open the default input device via `cpal` and keep `level` updated with a
+/// rolling RMS of the capture callback. Runs on its own OS thread because
+/// `cpal::Stream` isn't `Send`; if the device is unplugged or the stream
+/// errors out, it's rebuilt from scratch after a short delay, defaulting to
+/// "not in meeting" while no device is available. The microphone is only
+/// opened while `enabled` is true, so the app doesn't trip the OS's
+/// persistent "mic in use" indicator until the user turns on meeting
+/// detection.
+fn start_audio_capture_thread(level: Arc<Mutex<f32>>, enabled: Arc<Mutex<bool>>) {
+    std::thread::spawn(move || {
+        use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+
+        loop {
+            *level.lock().unwrap() = 0.0;
+
+            if !*enabled.lock().unwrap() {
+                std::thread::sleep(std::time::Duration::from_secs(1));
+                continue;
+            }
+
+            let host = cpal::default_host();
+            let device = match host.default_input_device() {
+                Some(d) => d,
+                None => {
+                    std::thread::sleep(std::time::Duration::from_secs(5));
+                    continue;
+                }
+            };
+
+            let config = match device.default_input_config() {
+                Ok(c) => c,
+                Err(_) => {
+                    std::thread::sleep(std::time::Duration::from_secs(5));
+                    continue;
+                }
+            };
+
+            let level_for_callback = level.clone();
+            let level_for_error = level.clone();
+            let stream_failed = Arc::new(std::sync::atomic::AtomicBool::new(false));
+            let stream_failed_for_error = stream_failed.clone();
+            let stream = device.build_input_stream(
+                &config.into(),
+                move |data: &[f32], _| {
+                    let sum_squares: f32 = data.iter().map(|s| s * s).sum();
+                    let rms = (sum_squares / data.len().max(1) as f32).sqrt();
+                    *level_for_callback.lock().unwrap() = rms;
+                },
+                move |_err| {
+                    // Device likely disconnected - report silence until we rebuild the stream
+                    *level_for_error.lock().unwrap() = 0.0;
+                    stream_failed_for_error.store(true, std::sync::atomic::Ordering::SeqCst);
+                },
+                None,
+            );
+
+            match stream {
+                Ok(stream) => {
+                    if stream.play().is_err() {
+                        std::thread::sleep(std::time::Duration::from_secs(5));
+                        continue;
+                    }
+                    // Keep this thread (and the stream) alive; cpal streams stop
+                    // producing callbacks once dropped. Drop it (by breaking out
+                    // and looping back to the `enabled` check) as soon as
+                    // meeting detection is turned off or the stream reports an
+                    // error, so a failed device is rebuilt from scratch.
+                    loop {
+                        std::thread::sleep(std::time::Duration::from_secs(1));
+                        if !*enabled.lock().unwrap() || stream_failed.load(std::sync::atomic::Ordering::SeqCst) {
+                            *level.lock().unwrap() = 0.0;
+                            break;
+                        }
+                    }
+                }
+                Err(_) => {
+                    std::thread::sleep(std::time::Duration::from_secs(5));
+                }
+            }
+        }
+    });
+}
+
+/// Poll the rolling audio level on a Tokio interval and accumulate how many
+/// seconds it's stayed above the configured threshold, which `is_in_meeting`
+/// then reads to decide whether breaks should be suppressed.
+fn start_meeting_detector(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+        loop {
+            interval.tick().await;
+
+            let state = match app.try_state::<AudioMonitorState>() {
+                Some(state) => state,
+                None => continue,
+            };
+
+            if !*state.enabled.lock().unwrap() {
+                *state.sustained_seconds_above_threshold.lock().unwrap() = 0.0;
+                continue;
+            }
+
+            let level = *state.level.lock().unwrap();
+            let threshold = *state.threshold.lock().unwrap();
+            let mut sustained = state.sustained_seconds_above_threshold.lock().unwrap();
+            if level >= threshold {
+                *sustained += 1.0;
+            } else {
+                *sustained = 0.0;
+            }
+        }
+    });
+}
+
+/// Where a `sidecar-*` event should be delivered. New sidecar event types
+/// get a line in `route_sidecar_event` rather than scattering window checks
+/// across the codebase.
+enum EventRoute {
+    /// Delivered only to windows whose label starts with one of these
+    /// prefixes, via `emit_filter`. A prefix (rather than an exact label)
+    /// so per-monitor mirrors like `overlay-monitor-1` are covered by the
+    /// same route as their primary window.
+    Windows(&'static [&'static str]),
+    /// Genuinely global events (pause/resume and anything not yet routed)
+    /// stay on the broadcast `emit` path
+    Broadcast,
+}
+
+/// Routing table mapping a sidecar `event_type` to the window(s) that
+/// actually care about it, so the session/overlay/notification frontends
+/// stop each having to filter out events meant for someone else.
+fn route_sidecar_event(event_type: &str) -> EventRoute {
+    match event_type {
+        "break_due" => EventRoute::Windows(&["overlay"]),
+        "schedule_warning" => EventRoute::Windows(&["notification"]),
+        "status_update" | "dashboard_update" | "settings_update" | "training_stats_update" => {
+            EventRoute::Windows(&["session"])
+        }
+        // pause/resume and anything unrecognized are genuinely global
+        _ => EventRoute::Broadcast,
+    }
+}
+
+/// Bitflags selecting which attributes of a window's geometry to
+/// persist/restore, so callers aren't forced to save everything at once
+mod window_geometry_flags {
+    pub const POSITION: u8 = 1 << 0;
+    pub const SIZE: u8 = 1 << 1;
+    pub const VISIBILITY: u8 = 1 << 2;
+    pub const ALL: u8 = POSITION | SIZE | VISIBILITY;
+}
+
+/// Saved geometry for a single window, serialized to the window-state file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct WindowGeometry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    x: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    y: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    maximized: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visible: Option<bool>,
+}
+
+/// Path to the JSON file window geometry is persisted to
+fn window_state_path(app: &AppHandle) -> Result<std::path::PathBuf, String> {
+    let dir = app.path().app_config_dir().map_err(|e| e.to_string())?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir.join("window-state.json"))
+}
+
+/// Load the full label -> geometry map from disk, defaulting to empty if
+/// the file doesn't exist yet or is unreadable
+fn load_window_states(app: &AppHandle) -> HashMap<String, WindowGeometry> {
+    let path = match window_state_path(app) {
+        Ok(p) => p,
+        Err(_) => return HashMap::new(),
+    };
+    std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+fn write_window_states(app: &AppHandle, states: &HashMap<String, WindowGeometry>) -> Result<(), String> {
+    let path = window_state_path(app)?;
+    let contents = serde_json::to_string_pretty(states).map_err(|e| e.to_string())?;
+    std::fs::write(&path, contents).map_err(|e| e.to_string())
+}
+
+/// Persist the selected attributes (`flags`, see `window_geometry_flags`) of
+/// the named window's current geometry
+fn save_window_state_for(app: &AppHandle, label: &str, flags: u8) -> Result<(), String> {
+    let window = match app.get_webview_window(label) {
+        Some(w) => w,
+        None => return Ok(()),
+    };
+
+    let mut states = load_window_states(app);
+    let entry = states.entry(label.to_string()).or_default();
+
+    if flags & window_geometry_flags::POSITION != 0 {
+        if let Ok(pos) = window.outer_position() {
+            entry.x = Some(pos.x);
+            entry.y = Some(pos.y);
+        }
+    }
+    if flags & window_geometry_flags::SIZE != 0 {
+        if let Ok(size) = window.outer_size() {
+            entry.width = Some(size.width);
+            entry.height = Some(size.height);
+        }
+    }
+    if flags & window_geometry_flags::VISIBILITY != 0 {
+        entry.maximized = window.is_maximized().ok();
+        entry.visible = window.is_visible().ok();
+    }
+
+    write_window_states(app, &states)
+}
+
+/// Apply previously saved geometry (if any) to the named window, restricted
+/// to the selected attributes
+fn restore_window_state_for(app: &AppHandle, label: &str, flags: u8) {
+    let window = match app.get_webview_window(label) {
+        Some(w) => w,
+        None => return,
+    };
+    let states = load_window_states(app);
+    let geometry = match states.get(label) {
+        Some(g) => g,
+        None => return,
+    };
+
+    if flags & window_geometry_flags::POSITION != 0 {
+        if let (Some(x), Some(y)) = (geometry.x, geometry.y) {
+            let _ = window.set_position(PhysicalPosition::new(x, y));
+        }
+    }
+    if flags & window_geometry_flags::SIZE != 0 {
+        if let (Some(width), Some(height)) = (geometry.width, geometry.height) {
+            let _ = window.set_size(PhysicalSize::new(width, height));
+        }
+    }
+    if flags & window_geometry_flags::VISIBILITY != 0 {
+        if geometry.maximized == Some(true) {
+            let _ = window.maximize();
+        }
+        match geometry.visible {
+            Some(true) => {
+                let _ = window.show();
+            }
+            Some(false) => {
+                let _ = window.hide();
+            }
+            None => {}
+        }
+    }
+}
+
+/// Persist a window's geometry (position, size, maximized/visible flags) to
+/// the app config dir
+#[tauri::command]
+fn save_window_state(app: AppHandle, label: String, flags: Option<u8>) -> Result<(), String> {
+    save_window_state_for(&app, &label, flags.unwrap_or(window_geometry_flags::ALL))
+}
+
+/// Restore a window's previously saved geometry
+#[tauri::command]
+fn restore_window_state(app: AppHandle, label: String, flags: Option<u8>) -> Result<(), String> {
+    restore_window_state_for(&app, &label, flags.unwrap_or(window_geometry_flags::ALL));
+    Ok(())
+}
+
+/// An emit that's been deferred until the frontend confirms it's alive
+struct PendingEmit {
+    /// `None` broadcasts app-wide; `Some(label)` targets one window
+    window_label: Option<String>,
+    event_name: String,
+    payload: serde_json::Value,
+}
+
+/// Gates outbound emits behind a real "frontend is alive" signal instead of
+/// a fixed sleep guessing how long WebView2 takes to mount. The Tokio event
+/// loop (`RunEvent::Ready`) must be up, and the *target* webview must have
+/// called the `frontend_ready` command itself, before anything queued for it is
+/// flushed - each window mounts its JS independently (this matters once
+/// `show_break_overlays_all_monitors`/`show_notification_overlays` create
+/// fresh mirror windows on demand), so readiness is tracked per label rather
+/// than as one global flag.
+struct ReadinessState {
+    loop_ready: Mutex<bool>,
+    frontend_ready: Mutex<HashSet<String>>,
+    pending: Mutex<Vec<PendingEmit>>,
+    /// Whether the app was launched with `--minimized` (autostart at system
+    /// boot), read once `main`'s frontend confirms it's alive to decide
+    /// whether to reveal it or leave it hidden in the tray
+    minimized_boot: Mutex<bool>,
+}
+
+/// Whether `window_label` (or, for a broadcast `None`, any window at all)
+/// has confirmed its frontend is ready to receive emits
+fn is_window_ready(state: &ReadinessState, window_label: &Option<String>) -> bool {
+    if !*state.loop_ready.lock().unwrap() {
+        return false;
+    }
+    let ready = state.frontend_ready.lock().unwrap();
+    match window_label {
+        Some(label) => ready.contains(label),
+        None => !ready.is_empty(),
+    }
+}
+
+fn deliver_emit(app: &AppHandle, window_label: &Option<String>, event_name: &str, payload: serde_json::Value) {
+    match window_label {
+        Some(label) => {
+            if let Some(window) = app.get_webview_window(label) {
+                let _ = window.emit(event_name, payload);
+            }
+        }
+        None => {
+            let _ = app.emit(event_name, payload);
+        }
+    }
+}
+
+/// Emit immediately if the target window's frontend is already confirmed
+/// ready, otherwise queue it for `flush_pending_emits` to deliver once it is
+fn queue_or_emit(app: &AppHandle, window_label: Option<&str>, event_name: &str, payload: serde_json::Value) {
+    let state = app.state::<ReadinessState>();
+    let label = window_label.map(String::from);
+    if is_window_ready(&state, &label) {
+        deliver_emit(app, &label, event_name, payload);
+    } else {
+        state.pending.lock().unwrap().push(PendingEmit {
+            window_label: label,
+            event_name: event_name.to_string(),
+            payload,
+        });
+    }
+}
+
+/// Flush any queued emits whose target window is now ready, leaving emits
+/// for still-not-ready windows queued behind
+fn flush_pending_emits(app: &AppHandle) {
+    let state = app.state::<ReadinessState>();
+    let (ready, still_pending): (Vec<PendingEmit>, Vec<PendingEmit>) =
+        std::mem::take(&mut *state.pending.lock().unwrap())
+            .into_iter()
+            .partition(|item| is_window_ready(&state, &item.window_label));
+    *state.pending.lock().unwrap() = still_pending;
+
+    for item in ready {
+        deliver_emit(app, &item.window_label, &item.event_name, item.payload);
+    }
+}
+
+/// Called by each webview once its own JS has mounted and attached
+/// listeners. Flushes anything queued for that window, and - for `main`
+/// specifically - reveals it now that the UI is confirmed alive, unless the
+/// app was launched with `--minimized`.
+#[tauri::command]
+fn frontend_ready(app: AppHandle, window: WebviewWindow) {
+    let label = window.label().to_string();
+    {
+        let state = app.state::<ReadinessState>();
+        state.frontend_ready.lock().unwrap().insert(label.clone());
+    }
+    flush_pending_emits(&app);
+
+    if label == "main" {
+        let state = app.state::<ReadinessState>();
+        let minimized = *state.minimized_boot.lock().unwrap();
+        if !minimized {
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
+}
+
+/// Default loopback address the control server listens on once enabled
+const DEFAULT_CONTROL_SERVER_ADDR: &str = "127.0.0.1:47291";
+
+/// State for the optional localhost control server used by scripts, CLI
+/// tooling, or a companion device to query/drive Aura without going through
+/// the Tauri webview at all
+struct ControlServerState {
+    /// Off by default - only scripts/tools that explicitly opt in via the
+    /// settings UI should be able to reach this
+    enabled: Mutex<bool>,
+    addr: Mutex<SocketAddr>,
+}
+
+/// Enable or disable the localhost control server, optionally rebinding it
+/// to a new loopback address
+#[tauri::command]
+fn set_control_server_enabled(state: State<ControlServerState>, enabled: bool, addr: Option<String>) -> Result<(), String> {
+    if let Some(addr) = addr {
+        let parsed: SocketAddr = addr.parse().map_err(|e| format!("Invalid address: {}", e))?;
+        if !parsed.ip().is_loopback() {
+            return Err("Control server address must be a loopback address".to_string());
+        }
+        *state.addr.lock().unwrap() = parsed;
+    }
+    *state.enabled.lock().unwrap() = enabled;
+    Ok(())
+}
+
+/// Handle one request line from a control-server client and return the JSON
+/// response - maps the same small surface as the existing invoke commands
+/// (`get_status`, `get_training_stats`, `trigger_test_break`,
+/// `pause_reminders`/`resume_reminders`, `reset_all_timers`).
+async fn handle_control_request(app: &AppHandle, line: &str) -> serde_json::Value {
+    let request: serde_json::Value = match serde_json::from_str(line) {
+        Ok(v) => v,
+        Err(e) => return serde_json::json!({ "error": format!("invalid JSON request: {}", e) }),
+    };
+    let cmd = request.get("cmd").and_then(|c| c.as_str()).unwrap_or("");
+
+    let result = match cmd {
+        "get_status" => send_sidecar_query(app, serde_json::json!({ "cmd": "get_status" })).await,
+        "get_training_stats" => send_sidecar_query(app, serde_json::json!({ "cmd": "get_training_stats" })).await,
+        "trigger_test_break" => {
+            let break_type = request.get("break_type").and_then(|v| v.as_str()).unwrap_or("eye_rest").to_string();
+            let duration_seconds = request.get("duration_seconds").and_then(|v| v.as_i64()).unwrap_or(20) as i32;
+            let theme_color = request.get("theme_color").and_then(|v| v.as_str()).unwrap_or("#4A90D9").to_string();
+            trigger_test_break(app.clone(), break_type, duration_seconds, theme_color).await.map(|_| serde_json::json!({ "ok": true }))
+        }
+        "pause_reminders" => {
+            let minutes = request.get("minutes").and_then(|v| v.as_i64()).map(|m| m as i32);
+            pause_reminders(app.clone(), minutes).await.map(|_| serde_json::json!({ "ok": true }))
+        }
+        "resume_reminders" => resume_reminders(app.clone()).await.map(|_| serde_json::json!({ "ok": true })),
+        "reset_all_timers" => reset_all_timers(app.clone()).await.map(|_| serde_json::json!({ "ok": true })),
+        other => Err(format!("Unknown control command: {}", other)),
+    };
+
+    match result {
+        Ok(value) => value,
+        Err(e) => serde_json::json!({ "error": e }),
+    }
+}
+
+/// Read newline-delimited JSON requests from one control-server connection
+/// and write back a JSON response per line
+async fn handle_control_connection(app: AppHandle, stream: TcpStream) {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let response = handle_control_request(&app, &line).await;
+        let mut out = response.to_string();
+        out.push('\n');
+        if writer.write_all(out.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Accept loop for the localhost control server. Runs for the lifetime of
+/// the app, binding/unbinding as `ControlServerState.enabled` is toggled so
+/// it stays off by default and only opens a socket once a user opts in.
+async fn run_control_server(app: AppHandle) {
+    loop {
+        let (enabled, addr) = {
+            let state = app.state::<ControlServerState>();
+            (*state.enabled.lock().unwrap(), *state.addr.lock().unwrap())
+        };
+
+        if !enabled {
+            tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+            continue;
+        }
+
+        let listener = match TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(e) => {
+                log::error!("[Aura] Failed to bind control server on {}: {}", addr, e);
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                continue;
+            }
+        };
+        log::info!("[Aura] Control server listening on {}", addr);
+
+        loop {
+            let (still_enabled, current_addr) = {
+                let state = app.state::<ControlServerState>();
+                (*state.enabled.lock().unwrap(), *state.addr.lock().unwrap())
+            };
+            if !still_enabled {
+                log::info!("[Aura] Control server disabled, closing listener");
+                break;
+            }
+            if current_addr != addr {
+                log::info!("[Aura] Control server address changed to {}, rebinding", current_addr);
+                break;
+            }
+
+            tokio::select! {
+                accept = listener.accept() => {
+                    if let Ok((stream, _)) = accept {
+                        tauri::async_runtime::spawn(handle_control_connection(app.clone(), stream));
+                    }
+                }
+                _ = tokio::time::sleep(std::time::Duration::from_secs(2)) => {}
+            }
+        }
+    }
+}
+
 /// Helper function to write command to sidecar stdin
 fn write_to_sidecar(app: &AppHandle, command: serde_json::Value) {
     if let Some(state) = app.try_state::<SidecarState>() {
@@ -374,6 +1380,9 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                 }
             }
             "pause_30m" => {
+                if let Some(idle_state) = app.try_state::<IdleState>() {
+                    *idle_state.user_paused.lock().unwrap() = true;
+                }
                 write_to_sidecar(app, serde_json::json!({
                     "cmd": "pause",
                     "minutes": 30
@@ -382,6 +1391,9 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                 let _ = app.emit("sidecar-paused", serde_json::json!({"minutes": 30}));
             }
             "pause_1h" => {
+                if let Some(idle_state) = app.try_state::<IdleState>() {
+                    *idle_state.user_paused.lock().unwrap() = true;
+                }
                 write_to_sidecar(app, serde_json::json!({
                     "cmd": "pause",
                     "minutes": 60
@@ -389,6 +1401,9 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                 let _ = app.emit("sidecar-paused", serde_json::json!({"minutes": 60}));
             }
             "pause_2h" => {
+                if let Some(idle_state) = app.try_state::<IdleState>() {
+                    *idle_state.user_paused.lock().unwrap() = true;
+                }
                 write_to_sidecar(app, serde_json::json!({
                     "cmd": "pause",
                     "minutes": 120
@@ -396,6 +1411,9 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                 let _ = app.emit("sidecar-paused", serde_json::json!({"minutes": 120}));
             }
             "pause_movie" => {
+                if let Some(idle_state) = app.try_state::<IdleState>() {
+                    *idle_state.user_paused.lock().unwrap() = true;
+                }
                 write_to_sidecar(app, serde_json::json!({
                     "cmd": "pause",
                     "minutes": 480
@@ -403,6 +1421,9 @@ fn setup_tray(app: &AppHandle) -> Result<(), Box<dyn std::error::Error>> {
                 let _ = app.emit("sidecar-paused", serde_json::json!({"minutes": 480}));
             }
             "resume" => {
+                if let Some(idle_state) = app.try_state::<IdleState>() {
+                    *idle_state.user_paused.lock().unwrap() = false;
+                }
                 write_to_sidecar(app, serde_json::json!({"cmd": "resume"}));
                 let _ = app.emit("sidecar-resumed", serde_json::json!({}));
             }
@@ -435,26 +1456,26 @@ fn start_sidecar(app: &AppHandle) {
     
     // In both development and production, use the bundled sidecar binary
     // The binary is built with PyInstaller and placed in src-tauri/
-    println!("[Aura] Creating sidecar command...");
+    log::info!("[Aura] Creating sidecar command...");
     let sidecar_cmd = match app_handle.shell().sidecar("aura-sidecar") {
         Ok(cmd) => {
-            println!("[Aura] Sidecar command created successfully");
+            log::info!("[Aura] Sidecar command created successfully");
             cmd
         }
         Err(e) => {
-            eprintln!("[Aura] Failed to create sidecar command: {}", e);
+            log::error!("[Aura] Failed to create sidecar command: {}", e);
             return;
         }
     };
     
-    println!("[Aura] Spawning sidecar...");
+    log::info!("[Aura] Spawning sidecar...");
     let (mut rx, child) = match sidecar_cmd.spawn() {
         Ok(result) => {
-            println!("[Aura] Sidecar spawned successfully");
+            log::info!("[Aura] Sidecar spawned successfully");
             result
         }
         Err(e) => {
-            eprintln!("[Aura] Failed to spawn sidecar: {}", e);
+            log::error!("[Aura] Failed to spawn sidecar: {}", e);
             return;
         }
     };
@@ -466,121 +1487,154 @@ fn start_sidecar(app: &AppHandle) {
     }
     
     *state.is_running.lock().unwrap() = true;
-    
+    *state.started_at.lock().unwrap() = Some(Instant::now());
+    *state.manually_stopped.lock().unwrap() = false;
+
     // Handle sidecar stdout events
     let app_for_events = app_handle.clone();
-    println!("[Aura] Starting event listener loop...");
+    log::info!("[Aura] Starting event listener loop...");
     tauri::async_runtime::spawn(async move {
-        println!("[Aura] Event loop started, waiting for sidecar output...");
+        log::info!("[Aura] Event loop started, waiting for sidecar output...");
         while let Some(event) = rx.recv().await {
             match event {
                 CommandEvent::Stdout(line) => {
                     let line_str = String::from_utf8_lossy(&line);
-                    println!("[Aura] Received stdout: {}", line_str);
+                    log::info!(target: "sidecar", "{}", line_str);
                     // Parse JSON and emit to frontend
                     match serde_json::from_slice::<SidecarEvent>(&line) {
                         Ok(sidecar_event) => {
+                            // A correlated query response resolves its waiting
+                            // oneshot directly; it's not a push event for the
+                            // frontend to listen for.
+                            if let Some(request_id) = sidecar_event.request_id {
+                                if let Some(pending) = app_for_events.try_state::<PendingRequests>() {
+                                    if let Some(tx) = pending.senders.lock().unwrap().remove(&request_id) {
+                                        let _ = tx.send(sidecar_event.data.clone().unwrap_or(serde_json::Value::Null));
+                                    }
+                                }
+                                continue;
+                            }
+
                             let event_name = format!("sidecar-{}", sidecar_event.event_type);
-                            println!("[Aura] Emitting event: {}", event_name);
-                            
+                            log::info!("[Aura] Emitting event: {}", event_name);
+
                             // IMPORTANT: Show overlay window when break is due
-                            if sidecar_event.event_type == "break_due" {
-                                println!("[Aura] Break due! Showing overlay window...");
-                                
+                            if sidecar_event.event_type == "break_due" && is_in_meeting(&app_for_events) {
+                                log::info!("[Aura] Break due during meeting - suppressing overlay, snoozing instead");
+                                write_to_sidecar(&app_for_events, serde_json::json!({
+                                    "cmd": "snooze_break",
+                                    "minutes": 5
+                                }));
+                                let _ = app_for_events.emit("sidecar-break-suppressed", sidecar_event.data.clone());
+                            } else if sidecar_event.event_type == "break_due" {
+                                log::info!("[Aura] Break due! Showing overlay on all monitors...");
+
                                 // Store break data in PendingBreakState so overlay can retrieve it
                                 if let Some(data) = &sidecar_event.data {
                                     if let Some(pending_state) = app_for_events.try_state::<PendingBreakState>() {
                                         *pending_state.break_data.lock().unwrap() = Some(data.clone());
                                     }
                                 }
-                                
-                                // Show overlay and emit event after delay
-                                if let Some(overlay) = app_for_events.get_webview_window("overlay") {
-                                    let _ = overlay.show();
-                                    let _ = overlay.set_focus();
-                                    
-                                    // Emit event after delay to give JS time to initialize
-                                    let break_data = sidecar_event.data.clone();
-                                    let app_clone = app_for_events.clone();
-                                    tauri::async_runtime::spawn(async move {
-                                        tokio::time::sleep(std::time::Duration::from_millis(300)).await;
-                                        if let Some(overlay) = app_clone.get_webview_window("overlay") {
-                                            if let Some(data) = break_data {
-                                                let _ = overlay.emit("show-break", data);
-                                            }
-                                        }
-                                    });
+
+                                if let Some(data) = sidecar_event.data.clone() {
+                                    show_break_overlays_all_monitors(&app_for_events, data);
                                 }
                             } else if sidecar_event.event_type == "schedule_warning" {
-                                println!("[Aura] Schedule warning! Showing notification window...");
-                                
-                                if let Some(window) = app_for_events.get_webview_window("notification") {
-                                    // Calculate position (Bottom-Right)
-                                    let monitor = window.current_monitor().ok().flatten()
-                                        .or_else(|| window.primary_monitor().ok().flatten());
-                                        
-                                    if let Some(monitor) = monitor {
-                                        let screen_size = monitor.size();
-                                        // Hardcoded window size (must match tauri.conf.json)
-                                        let window_width = 280;
-                                        let window_height = 320;
-                                        let padding = 20;
-                                        
-                                        // Calculate position
-                                        let x = (screen_size.width as i32) - window_width - padding;
-                                        let y = (screen_size.height as i32) - window_height - padding;
-                                        
-                                        let _ = window.set_position(PhysicalPosition::new(x, y));
-                                    }
-                                    
-                                    let _ = window.show();
-                                    // Use set_always_on_top to ensure visibility
-                                    let _ = window.set_always_on_top(true);
-                                    
-                                    // Emit event with data
-                                    let event_data = sidecar_event.data.clone();
-                                    let app_clone = app_for_events.clone();
-                                    tauri::async_runtime::spawn(async move {
-                                        // Small delay for frontend init
-                                        tokio::time::sleep(std::time::Duration::from_millis(800)).await;
-                                        if let Some(win) = app_clone.get_webview_window("notification") {
-                                            if let Some(data) = event_data {
-                                                let _ = win.emit("show-schedule-warning", data);
-                                            }
-                                        }
-                                    });
+                                log::info!("[Aura] Schedule warning! Showing notification window...");
+
+                                if let Some(data) = sidecar_event.data.clone() {
+                                    show_notification_overlays(&app_for_events, data);
                                 }
                             }
                             
-                            let _ = app_for_events.emit(&event_name, sidecar_event);
+                            match route_sidecar_event(&sidecar_event.event_type) {
+                                EventRoute::Windows(prefixes) => {
+                                    let _ = app_for_events.emit_filter(&event_name, sidecar_event, |w| {
+                                        prefixes.iter().any(|p| w.label().starts_with(p))
+                                    });
+                                }
+                                EventRoute::Broadcast => {
+                                    let _ = app_for_events.emit(&event_name, sidecar_event);
+                                }
+                            }
                         }
                         Err(e) => {
-                            eprintln!("[Aura] Failed to parse JSON: {} - raw: {}", e, line_str);
+                            log::error!("[Aura] Failed to parse JSON: {} - raw: {}", e, line_str);
                         }
                     }
                 }
                 CommandEvent::Stderr(line) => {
                     let line_str = String::from_utf8_lossy(&line);
-                    eprintln!("[Sidecar Error] {}", line_str);
+                    log::error!(target: "sidecar", "{}", line_str);
+                    if let Some(state) = app_for_events.try_state::<SidecarState>() {
+                        let mut history = state.last_stderr_lines.lock().unwrap();
+                        history.push_back(line_str.into_owned());
+                        while history.len() > SIDECAR_STDERR_HISTORY_LINES {
+                            history.pop_front();
+                        }
+                    }
                 }
                 CommandEvent::Error(err) => {
-                    eprintln!("[Sidecar] Error: {}", err);
+                    log::error!("[Sidecar] Error: {}", err);
                 }
                 CommandEvent::Terminated(status) => {
-                    eprintln!("[Sidecar] Terminated with status: {:?}", status);
-                    if let Some(state) = app_for_events.try_state::<SidecarState>() {
-                        *state.is_running.lock().unwrap() = false;
-                        // Clear the child reference
-                        if let Ok(mut child_guard) = state.child.lock() {
-                            *child_guard = None;
-                        }
+                    log::error!("[Sidecar] Terminated with status: {:?}", status);
+
+                    let state = match app_for_events.try_state::<SidecarState>() {
+                        Some(state) => state,
+                        None => continue,
+                    };
+
+                    *state.is_running.lock().unwrap() = false;
+                    if let Ok(mut child_guard) = state.child.lock() {
+                        *child_guard = None;
+                    }
+
+                    if *state.manually_stopped.lock().unwrap() {
+                        log::info!("[Aura] Sidecar was manually stopped, not restarting");
+                        continue;
+                    }
+
+                    // Reset the failure streak if the last instance stayed up
+                    // past the stability window
+                    let stayed_up = state.started_at.lock().unwrap()
+                        .map(|t| t.elapsed().as_secs() >= SIDECAR_STABILITY_WINDOW_SECS)
+                        .unwrap_or(false);
+                    if stayed_up {
+                        state.recent_restarts.lock().unwrap().clear();
+                    }
+
+                    let now = Instant::now();
+                    let restart_count = {
+                        let mut recent = state.recent_restarts.lock().unwrap();
+                        recent.retain(|t| now.duration_since(*t).as_secs() < SIDECAR_RAPID_RESTART_WINDOW_SECS);
+                        recent.push(now);
+                        recent.len()
+                    };
+
+                    if restart_count > SIDECAR_MAX_RAPID_RESTARTS {
+                        log::error!("[Aura] Sidecar crashed {} times within {}s, giving up", restart_count, SIDECAR_RAPID_RESTART_WINDOW_SECS);
+                        let last_stderr: Vec<String> = state.last_stderr_lines.lock().unwrap().iter().cloned().collect();
+                        let _ = app_for_events.emit("sidecar-failed", serde_json::json!({
+                            "restart_count": restart_count,
+                            "last_stderr": last_stderr,
+                        }));
+                        continue;
                     }
-                    // AUTO-RESTART: Try to restart sidecar after 2 seconds
+
+                    let backoff_secs = (SIDECAR_BACKOFF_BASE_SECS.saturating_mul(1u64 << (restart_count.min(6) as u32 - 1)))
+                        .min(SIDECAR_BACKOFF_MAX_SECS);
+                    let jitter_ms = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.subsec_millis() as u64 % 500)
+                        .unwrap_or(0);
+                    let delay = std::time::Duration::from_secs(backoff_secs) + std::time::Duration::from_millis(jitter_ms);
+
                     let app_restart = app_for_events.clone();
-                    println!("[Aura] Sidecar terminated, will attempt restart in 2 seconds...");
+                    log::info!("[Aura] Sidecar terminated, restart #{} in {:?}...", restart_count, delay);
                     tauri::async_runtime::spawn(async move {
-                        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-                        println!("[Aura] Attempting sidecar restart...");
+                        tokio::time::sleep(delay).await;
+                        log::info!("[Aura] Attempting sidecar restart...");
                         start_sidecar(&app_restart);
                     });
                 }
@@ -611,41 +1665,38 @@ async fn is_autostart_enabled(app: AppHandle) -> Result<bool, String> {
     app.autolaunch().is_enabled().map_err(|e| e.to_string())
 }
 
+/// Open the directory containing Aura's rotating log files
+#[tauri::command]
+fn open_log_directory(app: AppHandle) -> Result<(), String> {
+    use tauri_plugin_opener::OpenerExt;
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    app.opener().open_path(log_dir.to_string_lossy(), None::<&str>).map_err(|e| e.to_string())
+}
+
+/// Return the last `n` lines from Aura's current log file, so a
+/// "Report a problem" button can attach them without the user digging
+/// through the log directory themselves.
+#[tauri::command]
+fn get_recent_logs(app: AppHandle, n: usize) -> Result<Vec<String>, String> {
+    let log_dir = app.path().app_log_dir().map_err(|e| e.to_string())?;
+    let log_file = log_dir.join(format!("{}.log", app.package_info().name));
+    let contents = std::fs::read_to_string(&log_file).map_err(|e| e.to_string())?;
+    let lines: Vec<String> = contents.lines().map(String::from).collect();
+    let start = lines.len().saturating_sub(n);
+    Ok(lines[start..].to_vec())
+}
+
 /// Debug command to show notification window
 #[tauri::command]
 fn debug_notification(app: AppHandle) {
-    if let Some(window) = app.get_webview_window("notification") {
-        // Position logic
-        let monitor = window.current_monitor().ok().flatten()
-            .or_else(|| window.primary_monitor().ok().flatten());
-            
-        if let Some(monitor) = monitor {
-            let screen_size = monitor.size();
-            let window_width = 280;
-            let window_height = 320;
-            let padding = 20;
-            let x = (screen_size.width as i32) - window_width - padding;
-            let y = (screen_size.height as i32) - window_height - padding;
-            let _ = window.set_position(PhysicalPosition::new(x, y));
-        }
-        
-        let _ = window.show();
-        let _ = window.set_always_on_top(true);
+    show_notification_overlays(&app, serde_json::json!({
+        "title": "Debug Test Warning",
+        "action": "pause",
+        "seconds_remaining": 60
+    }));
+
+    if let Some(window) = app.get_webview_window(NOTIFICATION_BASE_LABEL) {
         let _ = window.set_focus();
-        
-        // Spawn async task to wait and emit, preventing main thread block
-        let window_clone = window.clone();
-        tauri::async_runtime::spawn(async move {
-            // Small delay to ensure frontend is ready
-            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
-            
-            // Emit dummy data
-            let _ = window_clone.emit("show-schedule-warning", serde_json::json!({
-                "title": "Debug Test Warning",
-                "action": "pause",
-                "seconds_remaining": 60
-            }));
-        });
     }
 }
 
@@ -655,16 +1706,63 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_autostart::init(MacosLauncher::LaunchAgent, Some(vec!["--minimized"])))
+        .plugin(
+            tauri_plugin_log::Builder::new()
+                .targets([
+                    tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::LogDir { file_name: None }),
+                    tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Stdout),
+                    tauri_plugin_log::Target::new(tauri_plugin_log::TargetKind::Webview),
+                ])
+                .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepAll)
+                .max_file_size(5_000_000)
+                .level(log::LevelFilter::Info)
+                .build(),
+        )
         .manage(SidecarState {
             is_running: Mutex::new(false),
             child: Mutex::new(None),
+            started_at: Mutex::new(None),
+            recent_restarts: Mutex::new(Vec::new()),
+            manually_stopped: Mutex::new(false),
+            last_stderr_lines: Mutex::new(VecDeque::new()),
         })
         .manage(PendingBreakState {
             break_data: Mutex::new(None),
         })
+        .manage(IdleState {
+            threshold_minutes: Mutex::new(5),
+            enabled: Mutex::new(true),
+            auto_paused: Mutex::new(false),
+            user_paused: Mutex::new(false),
+        })
+        .manage(AudioMonitorState {
+            level: Arc::new(Mutex::new(0.0)),
+            threshold: Mutex::new(DEFAULT_MIC_THRESHOLD),
+            enabled: Arc::new(Mutex::new(false)),
+            sustained_seconds_above_threshold: Mutex::new(0.0),
+        })
+        .manage(PendingRequests {
+            next_id: Mutex::new(0),
+            senders: Mutex::new(HashMap::new()),
+        })
+        .manage(ReadinessState {
+            loop_ready: Mutex::new(false),
+            frontend_ready: Mutex::new(HashSet::new()),
+            pending: Mutex::new(Vec::new()),
+            minimized_boot: Mutex::new(false),
+        })
+        .manage(ControlServerState {
+            enabled: Mutex::new(false),
+            addr: Mutex::new(DEFAULT_CONTROL_SERVER_ADDR.parse().expect("valid default control server address")),
+        })
+        .manage(MultiMonitorOverlayState {
+            enabled: Mutex::new(false),
+        })
         .invoke_handler(tauri::generate_handler![
             send_to_sidecar,
             is_sidecar_running,
+            stop_sidecar,
+            restart_sidecar,
             log_hydration,
             complete_break,
             snooze_break,
@@ -678,6 +1776,14 @@ pub fn run() {
             export_data,
             show_overlay,
             hide_overlay,
+            open_log_directory,
+            get_recent_logs,
+            save_window_state,
+            restore_window_state,
+            frontend_ready,
+            set_control_server_enabled,
+            set_multi_monitor_overlay_enabled,
+            show_overlay_all,
             debug_notification,
             trigger_test_break,
             get_pending_break,
@@ -696,39 +1802,105 @@ pub fn run() {
             update_schedule_rule,
             delete_schedule_rule,
             reset_all_timers,
+            // Idle detection
+            set_idle_threshold,
+            get_idle_status,
+            set_idle_detection_enabled,
+            // Meeting detection
+            get_audio_level,
+            set_mic_threshold,
+            set_meeting_detection_enabled,
         ])
         .setup(|app| {
+            // Restore remembered window geometry before anything is shown
+            for label in ["main", "overlay", "session", "notification"] {
+                restore_window_state_for(app.handle(), label, window_geometry_flags::ALL);
+            }
+
             // Check if started with --minimized flag (autostart at system boot)
             let args: Vec<String> = std::env::args().collect();
             let is_minimized = args.iter().any(|arg| arg == "--minimized");
-            
-            if is_minimized {
-                println!("[Aura] Started with --minimized flag, hiding main window");
-                // Hide the main window when started at system boot
-                // This prevents the broken Edge error page that can appear
-                // when WebView2 isn't fully ready at system startup
-                if let Some(window) = app.get_webview_window("main") {
-                    let _ = window.hide();
-                }
+            *app.state::<ReadinessState>().minimized_boot.lock().unwrap() = is_minimized;
+
+            // Hide the main window until its frontend confirms it's alive
+            // (see `frontend_ready`). This prevents the broken Edge error
+            // page that can appear when WebView2 isn't fully ready at
+            // startup, and doubles as the `--minimized` autostart behavior:
+            // `frontend_ready` only reveals it once the UI is up, and leaves
+            // it hidden in the tray when `is_minimized` is set.
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.hide();
             }
-            
+
             // Setup system tray
             if let Err(e) = setup_tray(app.handle()) {
-                eprintln!("Failed to setup tray: {}", e);
+                log::error!("Failed to setup tray: {}", e);
             }
             
             // Start Python sidecar (even when minimized, we need the engine running)
             start_sidecar(app.handle());
-            
+
+            // Start polling system idle time so we can auto-pause/resume the
+            // session. A one-shot loop reading from managed state, so it
+            // belongs here rather than in start_sidecar (which re-runs on
+            // every restart).
+            start_idle_monitor(app.handle());
+
+            // Start the microphone-based meeting detector
+            let audio_state = app.state::<AudioMonitorState>();
+            start_audio_capture_thread(audio_state.level.clone(), audio_state.enabled.clone());
+            start_meeting_detector(app.handle());
+
+            // Start the localhost control server (stays idle until enabled)
+            tauri::async_runtime::spawn(run_control_server(app.handle().clone()));
+
             Ok(())
         })
         .on_window_event(|window, event| {
-            // Minimize to tray on close
-            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
-                window.hide().unwrap();
-                api.prevent_close();
+            match event {
+                tauri::WindowEvent::CloseRequested { api, .. } => {
+                    // Minimize to tray on close
+                    let _ = save_window_state_for(window.app_handle(), window.label(), window_geometry_flags::ALL);
+                    window.hide().unwrap();
+                    api.prevent_close();
+                }
+                tauri::WindowEvent::Moved(_) => {
+                    let _ = save_window_state_for(window.app_handle(), window.label(), window_geometry_flags::POSITION);
+                }
+                tauri::WindowEvent::Resized(_) => {
+                    let _ = save_window_state_for(window.app_handle(), window.label(), window_geometry_flags::SIZE | window_geometry_flags::VISIBILITY);
+                }
+                _ => {}
             }
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // Every quit path (tray "Quit", app.exit(), OS shutdown) routes
+            // through ExitRequested -> Exit, so this is the single place we
+            // need to tear down the sidecar child process to avoid leaving
+            // an orphaned Python engine running after Aura is killed.
+            match event {
+                tauri::RunEvent::Ready => {
+                    let state = app.state::<ReadinessState>();
+                    *state.loop_ready.lock().unwrap() = true;
+                    flush_pending_emits(app);
+                }
+                tauri::RunEvent::ExitRequested { .. } => {
+                    log::info!("[Aura] Exit requested, shutting down sidecar...");
+                    write_to_sidecar(app, serde_json::json!({ "cmd": "shutdown" }));
+                }
+                tauri::RunEvent::Exit => {
+                    let state = app.state::<SidecarState>();
+                    *state.manually_stopped.lock().unwrap() = true;
+                    if let Ok(mut child_guard) = state.child.lock() {
+                        if let Some(mut child) = child_guard.take() {
+                            let _ = child.kill();
+                        }
+                    }
+                    *state.is_running.lock().unwrap() = false;
+                }
+                _ => {}
+            }
+        });
 }